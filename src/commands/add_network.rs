@@ -0,0 +1,23 @@
+use anyhow::Result;
+
+use crate::user_config::{add_network as add_network_entry, NetworkConfig};
+
+pub fn execute(
+    chain_id: u64,
+    name: String,
+    rpc: String,
+    tx_pool: String,
+    config: Option<String>,
+) -> Result<()> {
+    let network = NetworkConfig {
+        chain_id,
+        name: name.clone(),
+        rpc,
+        tx_pool_address: tx_pool,
+    };
+
+    let path = add_network_entry(config.as_deref(), network)?;
+    println!("Added {} (Chain ID: {}) to {}", name, chain_id, path.display());
+
+    Ok(())
+}
@@ -0,0 +1,184 @@
+use anyhow::{bail, Context, Result};
+use ethers::{
+    middleware::{gas_oracle::GasOracleMiddleware, NonceManagerMiddleware, Middleware, SignerMiddleware},
+    providers::{Http, Provider},
+    signers::{HDPath, Ledger, LocalWallet, Signer},
+    types::{Address, Bytes, H256, U256},
+};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use super::pending_tx::{connect_and_fetch, TxDetails};
+use crate::config::DEFAULT_MAINNET_RPC;
+use crate::contracts::Safe;
+use crate::gas_oracle::build_gas_oracle;
+use crate::signature::recover_signer;
+use crate::user_config::load_registry;
+
+#[allow(clippy::too_many_arguments)]
+pub async fn execute(
+    safe: String,
+    rpc: Option<String>,
+    hash: String,
+    tx_pool: Option<String>,
+    etherscan_key: Option<String>,
+    ledger: bool,
+    derivation_path: Option<String>,
+    keystore: Option<String>,
+    private_key: Option<String>,
+    network: Option<String>,
+    config: Option<String>,
+) -> Result<()> {
+    let safe_address = Address::from_str(&safe).context("Invalid Safe wallet address format")?;
+    let tx_hash = H256::from_str(&hash).context("Invalid transaction hash format")?;
+
+    let registry = load_registry(config.as_deref())?;
+
+    let rpc_url = match (rpc, network.as_deref()) {
+        (Some(explicit), _) => explicit,
+        (None, Some(name)) => registry
+            .rpc_for_name(name)
+            .with_context(|| format!("Unknown network '{}' in config file", name))?,
+        (None, None) => {
+            println!("No RPC URL provided, using default Ethereum mainnet RPC");
+            DEFAULT_MAINNET_RPC.to_string()
+        }
+    };
+
+    let pending = connect_and_fetch(&safe, safe_address, &hash, tx_hash, rpc_url, tx_pool, &registry).await?;
+    let network_id = pending.chain_id.as_u64();
+
+    let raw_signatures = pending.contract
+        .get_signatures(tx_hash.into())
+        .call()
+        .await
+        .context("Failed to fetch transaction signatures")?;
+
+    let safe_contract = Safe::new(safe_address, pending.provider.clone());
+    let owners = safe_contract.get_owners().call().await.context("Failed to fetch Safe owners")?;
+    let threshold = safe_contract.get_threshold().call().await.context("Failed to fetch Safe threshold")?;
+
+    let signatures = collect_ordered_signatures(pending.digest, &raw_signatures, &owners, threshold)?;
+
+    match (ledger, keystore, private_key) {
+        (true, None, None) => {
+            let path = match derivation_path {
+                Some(custom) => HDPath::Other(custom),
+                None => HDPath::LedgerLive(0),
+            };
+            let signer = Ledger::new(path, network_id).await.context("Failed to connect to Ledger device")?;
+            submit_exec(pending.provider, signer, safe_address, pending.tx_details, signatures, etherscan_key).await
+        }
+        (false, Some(path), _) => {
+            let password = rpassword::prompt_password("Keystore password: ")
+                .context("Failed to read keystore password")?;
+            let signer = LocalWallet::decrypt_keystore(&path, &password).context("Failed to decrypt keystore")?;
+            submit_exec(pending.provider, signer, safe_address, pending.tx_details, signatures, etherscan_key).await
+        }
+        (false, None, Some(pk)) => {
+            let signer = LocalWallet::from_str(&pk).context("Invalid private key")?;
+            submit_exec(pending.provider, signer, safe_address, pending.tx_details, signatures, etherscan_key).await
+        }
+        (false, None, None) => bail!("Specify a signer: --ledger, --keystore <path>, or --private-key <key>"),
+        _ => bail!("Specify exactly one signer source: --ledger, --keystore, or --private-key"),
+    }
+}
+
+/// Recover each signature's owner, drop non-owners and duplicates, and
+/// concatenate the remaining signatures in ascending signer-address order,
+/// which is what `execTransaction` requires.
+fn collect_ordered_signatures(
+    digest: H256,
+    raw_signatures: &[Bytes],
+    owners: &[Address],
+    threshold: U256,
+) -> Result<Bytes> {
+    let mut by_signer: Vec<(Address, Vec<u8>)> = Vec::new();
+    for raw in raw_signatures {
+        match recover_signer(digest, raw) {
+            Ok(recovered) if owners.contains(&recovered.signer) => {
+                if !by_signer.iter().any(|(addr, _)| *addr == recovered.signer) {
+                    by_signer.push((recovered.signer, raw.to_vec()));
+                }
+            }
+            Ok(recovered) => {
+                println!("Warning: ignoring signature from non-owner {:?}", recovered.signer);
+            }
+            Err(e) => {
+                println!("Warning: failed to recover a signature: {}", e);
+            }
+        }
+    }
+
+    if U256::from(by_signer.len()) < threshold {
+        bail!(
+            "Only {} of the required {} signatures are available for this transaction",
+            by_signer.len(),
+            threshold
+        );
+    }
+
+    by_signer.sort_by_key(|(signer, _)| *signer);
+
+    let concatenated: Vec<u8> = by_signer.into_iter().flat_map(|(_, sig)| sig).collect();
+    Ok(Bytes::from(concatenated))
+}
+
+async fn submit_exec<S>(
+    provider: Arc<Provider<Http>>,
+    signer: S,
+    safe_address: Address,
+    tx_details: TxDetails,
+    signatures: Bytes,
+    etherscan_key: Option<String>,
+) -> Result<()>
+where
+    S: Signer + Clone + 'static,
+{
+    let chain_id = provider.get_chainid().await.context("Failed to get chain ID from network")?.as_u64();
+    let signer = signer.with_chain_id(chain_id);
+    let sender = signer.address();
+    println!("Submitting execTransaction from {:?}", sender);
+
+    let gas_oracle = build_gas_oracle(chain_id, provider.clone(), etherscan_key);
+
+    let client = SignerMiddleware::new(provider, signer);
+    let client = NonceManagerMiddleware::new(client, sender);
+    let client = GasOracleMiddleware::new(client, gas_oracle);
+    let client = Arc::new(client);
+
+    let safe_contract = Safe::new(safe_address, client);
+
+    // safeTxGas/baseGas/gasPrice/gasToken/refundReceiver are zero-filled:
+    // SafeTxPool doesn't track them, and the digest signed in eip712.rs
+    // zero-fills the same fields, so this matches what was actually signed.
+    let call = safe_contract.exec_transaction(
+        tx_details.1,
+        tx_details.2,
+        tx_details.3,
+        tx_details.4,
+        U256::zero(),
+        U256::zero(),
+        U256::zero(),
+        Address::zero(),
+        Address::zero(),
+        signatures,
+    );
+
+    let estimated_gas = call.estimate_gas().await.context("Failed to estimate gas for execTransaction")?;
+    println!("Estimated gas: {}", estimated_gas);
+
+    let pending_tx = call
+        .gas(estimated_gas)
+        .send()
+        .await
+        .context("Failed to submit execTransaction")?;
+    println!("Submitted execTransaction {:?}, waiting for confirmation...", pending_tx.tx_hash());
+
+    match pending_tx.await.context("Failed while waiting for execTransaction to be mined")? {
+        Some(receipt) => println!("Executed. Transaction hash: {:?}", receipt.transaction_hash),
+        None => println!("Submitted, but no receipt was returned"),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,6 @@
+pub mod add_network;
+pub mod exec;
+mod pending_tx;
+pub mod sign;
+pub mod tx;
+pub mod watch;
@@ -0,0 +1,82 @@
+// Shared "connect, resolve the transaction pool, and fetch + validate a
+// pending transaction" step used by both `sign` and `exec`, which otherwise
+// diverge only once they have the transaction's EIP-712 digest in hand.
+use anyhow::{bail, Context, Result};
+use ethers::{
+    middleware::Middleware,
+    providers::{Http, Provider},
+    types::{Address, Bytes, H256, U256},
+};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::contracts::SafeTxPool;
+use crate::eip712::safe_tx_hash;
+use crate::user_config::NetworkRegistry;
+
+pub(crate) type TxDetails = (Address, Address, U256, Bytes, u8, Address, U256);
+
+/// Everything `sign` and `exec` need after connecting: the provider, the
+/// `SafeTxPool` contract handle, the chain id, the validated transaction
+/// details, and the EIP-712 digest an owner signs for it.
+pub(crate) struct PendingTx {
+    pub provider: Arc<Provider<Http>>,
+    pub contract: SafeTxPool<Provider<Http>>,
+    pub chain_id: U256,
+    pub tx_details: TxDetails,
+    pub digest: H256,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn connect_and_fetch(
+    safe: &str,
+    safe_address: Address,
+    hash: &str,
+    tx_hash: H256,
+    rpc_url: String,
+    tx_pool: Option<String>,
+    registry: &NetworkRegistry,
+) -> Result<PendingTx> {
+    let provider = Provider::<Http>::try_from(rpc_url).context("Failed to connect to RPC provider")?;
+    let provider = Arc::new(provider);
+
+    let chain_id = provider.get_chainid().await.context("Failed to get chain ID from network")?;
+    let network_id = chain_id.as_u64();
+    println!("Connected to {} (Chain ID: {})", registry.network_name(network_id), network_id);
+
+    let tx_pool_address = match tx_pool {
+        Some(custom) => Address::from_str(&custom).context("Invalid custom Safe transaction pool address")?,
+        None => {
+            let tx_pool_address_str = registry.tx_pool_address(network_id);
+            println!("Using Safe transaction pool at {} for {}", tx_pool_address_str, registry.network_name(network_id));
+            Address::from_str(&tx_pool_address_str).context("Invalid Safe transaction pool address")?
+        }
+    };
+
+    let contract = SafeTxPool::new(tx_pool_address, provider.clone());
+    let tx_details: TxDetails = contract
+        .get_tx_details(tx_hash.into())
+        .call()
+        .await
+        .context("Failed to fetch transaction details")?;
+
+    if tx_details.5 == Address::zero() {
+        bail!("Transaction not found or has already been executed");
+    }
+
+    if safe_address != tx_details.0 {
+        bail!("Transaction {} does not belong to Safe {}", hash, safe);
+    }
+
+    let digest = safe_tx_hash(
+        chain_id,
+        tx_details.0,
+        tx_details.1,
+        tx_details.2,
+        &tx_details.3,
+        tx_details.4,
+        tx_details.6,
+    );
+
+    Ok(PendingTx { provider, contract, chain_id, tx_details, digest })
+}
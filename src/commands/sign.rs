@@ -0,0 +1,122 @@
+use anyhow::{bail, Context, Result};
+use ethers::{
+    middleware::{Middleware, SignerMiddleware},
+    providers::{Http, Provider},
+    signers::{HDPath, Ledger, LocalWallet, Signer},
+    types::{Address, Bytes, H256},
+};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use super::pending_tx::connect_and_fetch;
+use crate::config::DEFAULT_MAINNET_RPC;
+use crate::contracts::SafeTxPool;
+use crate::user_config::load_registry;
+
+/// Offset added to the recovery id of an `eth_sign`-style signature so the
+/// Safe contract can tell it apart from a raw ECDSA (`eth_signTypedData`) one.
+const ETH_SIGN_V_OFFSET: u8 = 4;
+
+#[allow(clippy::too_many_arguments)]
+pub async fn execute(
+    safe: String,
+    rpc: Option<String>,
+    hash: String,
+    tx_pool: Option<String>,
+    ledger: bool,
+    derivation_path: Option<String>,
+    keystore: Option<String>,
+    private_key: Option<String>,
+    network: Option<String>,
+    config: Option<String>,
+) -> Result<()> {
+    let safe_address = Address::from_str(&safe).context("Invalid Safe wallet address format")?;
+    let tx_hash = H256::from_str(&hash).context("Invalid transaction hash format")?;
+
+    let registry = load_registry(config.as_deref())?;
+
+    let rpc_url = match (rpc, network.as_deref()) {
+        (Some(explicit), _) => explicit,
+        (None, Some(name)) => registry
+            .rpc_for_name(name)
+            .with_context(|| format!("Unknown network '{}' in config file", name))?,
+        (None, None) => {
+            println!("No RPC URL provided, using default Ethereum mainnet RPC");
+            DEFAULT_MAINNET_RPC.to_string()
+        }
+    };
+
+    let pending = connect_and_fetch(&safe, safe_address, &hash, tx_hash, rpc_url, tx_pool, &registry).await?;
+    println!("Safe transaction hash to sign: 0x{}", hex::encode(pending.digest.as_bytes()));
+
+    let network_id = pending.chain_id.as_u64();
+    let tx_pool_address = pending.contract.address();
+
+    match (ledger, keystore, private_key) {
+        (true, None, None) => {
+            let path = match derivation_path {
+                Some(custom) => HDPath::Other(custom),
+                None => HDPath::LedgerLive(0),
+            };
+            let signer = Ledger::new(path, network_id)
+                .await
+                .context("Failed to connect to Ledger device")?;
+            sign_and_submit(pending.provider, signer, tx_pool_address, tx_hash, pending.digest).await
+        }
+        (false, Some(path), _) => {
+            let password = rpassword::prompt_password("Keystore password: ")
+                .context("Failed to read keystore password")?;
+            let signer = LocalWallet::decrypt_keystore(&path, &password)
+                .context("Failed to decrypt keystore")?;
+            sign_and_submit(pending.provider, signer, tx_pool_address, tx_hash, pending.digest).await
+        }
+        (false, None, Some(pk)) => {
+            let signer = LocalWallet::from_str(&pk).context("Invalid private key")?;
+            sign_and_submit(pending.provider, signer, tx_pool_address, tx_hash, pending.digest).await
+        }
+        (false, None, None) => bail!("Specify a signer: --ledger, --keystore <path>, or --private-key <key>"),
+        _ => bail!("Specify exactly one signer source: --ledger, --keystore, or --private-key"),
+    }
+}
+
+async fn sign_and_submit<S>(
+    provider: Arc<Provider<Http>>,
+    signer: S,
+    tx_pool_address: Address,
+    tx_hash: H256,
+    digest: H256,
+) -> Result<()>
+where
+    S: Signer + Clone + 'static,
+{
+    let chain_id = provider.get_chainid().await.context("Failed to get chain ID from network")?;
+    let signer = signer.with_chain_id(chain_id.as_u64());
+    let signer_address = signer.address();
+    println!("Signing with {:?}", signer_address);
+
+    // Safe signers sign over the raw digest using personal_sign (eth_sign),
+    // so the resulting recovery id needs the +4 offset the contract expects.
+    let signature = signer
+        .sign_message(digest.as_bytes())
+        .await
+        .context("Failed to sign Safe transaction hash")?;
+
+    let mut signature_bytes = signature.to_vec();
+    signature_bytes[64] += ETH_SIGN_V_OFFSET;
+
+    let client = Arc::new(SignerMiddleware::new(provider, signer));
+    let contract = SafeTxPool::new(tx_pool_address, client);
+
+    let pending_tx = contract
+        .sign_tx(tx_hash.into(), Bytes::from(signature_bytes))
+        .send()
+        .await
+        .context("Failed to submit signature to SafeTxPool")?;
+
+    match pending_tx.await.context("Failed while waiting for the signTx transaction to be mined")? {
+        Some(receipt) => println!("Signature submitted by {:?}. Transaction hash: {:?}", signer_address, receipt.transaction_hash),
+        None => println!("Signature submitted by {:?}, but no receipt was returned", signer_address),
+    }
+
+    Ok(())
+}
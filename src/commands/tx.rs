@@ -1,24 +1,24 @@
 use anyhow::{Result, Context, bail};
 use std::str::FromStr;
 use ethers::{
+    contract::decode_function_data,
     providers::{Provider, Http},
-    types::{Address, H256},
-    contract::{abigen},
+    types::{Address, Bytes, H256, U256},
     middleware::Middleware,
 };
 use std::sync::Arc;
-use crate::config::{get_safe_tx_pool_address, get_network_name, DEFAULT_MAINNET_RPC};
-
-// Generate contract bindings using the exact ABI from the contract at commit 3658aca34ee38cba8e5bb9ed90927c270df8584d
-abigen!(
-    SafeTxPool,
-    r#"[
-        function getTxDetails(bytes32 txHash) external view returns (address safe, address to, uint256 value, bytes data, uint8 operation, address proposer, uint256 nonce)
-        function getSignatures(bytes32 txHash) external view returns (bytes[] memory)
-        function getPendingTxHashes(address safe) external view returns (bytes32[] memory)
-        function hasSignedTx(bytes32 txHash, address signer) external view returns (bool)
-    ]"#
-);
+use crate::config::{DEFAULT_MAINNET_RPC, MULTICALL3_ADDRESS};
+use crate::contracts::{Call3, Multicall3, Safe, SafeTxPool};
+use crate::decode::{build_explorer_client, decode_calldata, DecodedCall};
+use crate::eip712::safe_tx_hash;
+use crate::signature::recover_signer;
+use crate::user_config::load_registry;
+use ethers::etherscan::Client as EtherscanClient;
+
+/// `operation` value used by the Safe contracts for a DELEGATECALL.
+const OPERATION_DELEGATECALL: u8 = 1;
+
+type TxDetails = (Address, Address, U256, Bytes, u8, Address, U256);
 
 // Define a struct to hold transaction data in a more user-friendly format
 #[derive(serde::Serialize)]
@@ -32,38 +32,296 @@ struct TransactionData {
     proposer: String,
     nonce: String,
     signatures: Vec<String>,
+    signers_recovered: Vec<String>,
+    threshold: String,
+    executable: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    decoded: Option<DecodedCall>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    warning: Option<String>,
+}
+
+/// Owner set and signing threshold for a Safe, fetched once and reused for
+/// every pending transaction so we don't re-query the Safe per transaction.
+struct SafeSigningStatus {
+    owners: Vec<Address>,
+    threshold: U256,
+}
+
+async fn fetch_signing_status(
+    safe_address: Address,
+    provider: Arc<Provider<Http>>,
+) -> Result<SafeSigningStatus> {
+    let safe = Safe::new(safe_address, provider);
+
+    let owners = safe
+        .get_owners()
+        .call()
+        .await
+        .context("Failed to fetch Safe owners")?;
+    let threshold = safe
+        .get_threshold()
+        .call()
+        .await
+        .context("Failed to fetch Safe threshold")?;
+
+    Ok(SafeSigningStatus { owners, threshold })
+}
+
+/// Recover the distinct owner signers of a pending transaction and report
+/// whether enough of them have signed to meet the Safe's threshold.
+fn signing_summary(
+    chain_id: U256,
+    safe_address: Address,
+    to: Address,
+    value: U256,
+    data: &Bytes,
+    operation: u8,
+    nonce: U256,
+    raw_signatures: &[Bytes],
+    status: &SafeSigningStatus,
+) -> (Vec<String>, bool) {
+    let digest = safe_tx_hash(chain_id, safe_address, to, value, data, operation, nonce);
+
+    let mut signers = Vec::new();
+    for raw in raw_signatures {
+        match recover_signer(digest, raw) {
+            Ok(recovered) if status.owners.contains(&recovered.signer) => {
+                let signer_str = format!("0x{:x}", recovered.signer);
+                if !signers.contains(&signer_str) {
+                    signers.push(signer_str);
+                }
+            }
+            Ok(recovered) => {
+                println!("Warning: signature recovered to {:?}, which is not a Safe owner", recovered.signer);
+            }
+            Err(e) => {
+                println!("Warning: failed to recover a signature: {}", e);
+            }
+        }
+    }
+
+    let executable = U256::from(signers.len()) >= status.threshold;
+    (signers, executable)
+}
+
+fn delegatecall_warning(operation: u8) -> Option<String> {
+    if operation == OPERATION_DELEGATECALL {
+        Some("DELEGATECALL: this transaction executes in the Safe's own storage context and can change its owners, threshold, or modules. Review the call target carefully.".to_string())
+    } else {
+        None
+    }
+}
+
+/// Assemble the user-facing `TransactionData` for one pending transaction.
+async fn build_tx_data(
+    tx_hash: H256,
+    tx_details: TxDetails,
+    raw_signatures: Vec<Bytes>,
+    chain_id: U256,
+    signing_status: &SafeSigningStatus,
+    explorer_client: &Option<EtherscanClient>,
+) -> TransactionData {
+    let signature_strings: Vec<String> = raw_signatures.iter()
+        .map(|sig| format!("0x{}", hex::encode(sig.to_vec())))
+        .collect();
+
+    let (signers_recovered, executable) = signing_summary(
+        chain_id,
+        tx_details.0,
+        tx_details.1,
+        tx_details.2,
+        &tx_details.3,
+        tx_details.4,
+        tx_details.6,
+        &raw_signatures,
+        signing_status,
+    );
+
+    let decoded = match explorer_client {
+        Some(client) => decode_calldata(client, tx_details.1, &tx_details.3).await,
+        None => None,
+    };
+
+    TransactionData {
+        hash: format!("0x{}", hex::encode(tx_hash.as_bytes())),
+        safe: format!("0x{:x}", tx_details.0),
+        to: format!("0x{:x}", tx_details.1),
+        value: tx_details.2.to_string(),
+        data: format!("0x{}", hex::encode(tx_details.3.to_vec())),
+        operation: tx_details.4,
+        proposer: format!("0x{:x}", tx_details.5),
+        nonce: tx_details.6.to_string(),
+        signatures: signature_strings,
+        signers_recovered,
+        threshold: signing_status.threshold.to_string(),
+        executable,
+        decoded,
+        warning: delegatecall_warning(tx_details.4),
+    }
 }
 
-pub async fn execute(safe: String, rpc: Option<String>, hash: Option<String>, tx_pool: Option<String>) -> Result<()> {
+/// Fetch every pending transaction's details and signatures with one
+/// `getTxDetails`/`getSignatures` round-trip per hash.
+async fn fetch_pending_sequential(
+    contract: &SafeTxPool<Provider<Http>>,
+    tx_hashes: &[H256],
+    chain_id: U256,
+    signing_status: &SafeSigningStatus,
+    explorer_client: &Option<EtherscanClient>,
+) -> Vec<TransactionData> {
+    let mut transactions = Vec::new();
+    for tx_hash in tx_hashes {
+        match contract.get_tx_details((*tx_hash).into()).call().await {
+            Ok(tx_details) => {
+                let raw_signatures = contract.get_signatures((*tx_hash).into()).call().await.unwrap_or_default();
+                transactions.push(
+                    build_tx_data(*tx_hash, tx_details, raw_signatures, chain_id, signing_status, explorer_client).await,
+                );
+            }
+            Err(e) => {
+                println!("Warning: Failed to fetch details for transaction {:?}: {}", tx_hash, e);
+            }
+        }
+    }
+    transactions
+}
+
+/// Fetch every pending transaction's details and signatures in a single
+/// Multicall3 `aggregate3` call instead of 2N sequential RPC requests.
+async fn fetch_pending_via_multicall(
+    contract: &SafeTxPool<Provider<Http>>,
+    provider: Arc<Provider<Http>>,
+    tx_hashes: &[H256],
+    chain_id: U256,
+    signing_status: &SafeSigningStatus,
+    explorer_client: &Option<EtherscanClient>,
+) -> Result<Vec<TransactionData>> {
+    let multicall_address = Address::from_str(MULTICALL3_ADDRESS)
+        .context("Invalid Multicall3 address")?;
+    let multicall = Multicall3::new(multicall_address, provider);
+
+    let mut calls = Vec::with_capacity(tx_hashes.len() * 2);
+    for tx_hash in tx_hashes {
+        let details_call = contract.get_tx_details((*tx_hash).into());
+        let signatures_call = contract.get_signatures((*tx_hash).into());
+
+        calls.push(Call3 {
+            target: contract.address(),
+            allow_failure: true,
+            call_data: details_call.calldata().context("Failed to encode getTxDetails call")?,
+        });
+        calls.push(Call3 {
+            target: contract.address(),
+            allow_failure: true,
+            call_data: signatures_call.calldata().context("Failed to encode getSignatures call")?,
+        });
+    }
+
+    let results = multicall
+        .aggregate3(calls)
+        .call()
+        .await
+        .context("Multicall3 aggregate3 call failed")?;
+
+    let mut transactions = Vec::with_capacity(tx_hashes.len());
+    for (i, tx_hash) in tx_hashes.iter().enumerate() {
+        let details_result = &results[i * 2];
+        let signatures_result = &results[i * 2 + 1];
+
+        if !details_result.success {
+            println!("Warning: multicall getTxDetails failed for {:?}", tx_hash);
+            continue;
+        }
+
+        let tx_details: TxDetails = decode_function_data(
+            &contract.get_tx_details(Default::default()).function,
+            &details_result.return_data,
+            false,
+        )
+        .context("Failed to decode getTxDetails result from multicall")?;
+
+        let raw_signatures: Vec<Bytes> = if signatures_result.success {
+            decode_function_data(
+                &contract.get_signatures(Default::default()).function,
+                &signatures_result.return_data,
+                false,
+            )
+            .unwrap_or_default()
+        } else {
+            vec![]
+        };
+
+        transactions.push(
+            build_tx_data(*tx_hash, tx_details, raw_signatures, chain_id, signing_status, explorer_client).await,
+        );
+    }
+
+    Ok(transactions)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn execute(
+    safe: String,
+    rpc: Option<String>,
+    hash: Option<String>,
+    tx_pool: Option<String>,
+    decode: bool,
+    etherscan_key: Option<String>,
+    no_multicall: bool,
+    network: Option<String>,
+    config: Option<String>,
+) -> Result<()> {
     // Validate safe address
     let safe_address = Address::from_str(&safe)
         .context("Invalid Safe wallet address format")?;
 
-    // Use the provided RPC or the default mainnet RPC
-    let rpc_url = rpc.unwrap_or_else(|| {
-        println!("No RPC URL provided, using default Ethereum mainnet RPC");
-        DEFAULT_MAINNET_RPC.to_string()
-    });
+    let registry = load_registry(config.as_deref())?;
+
+    // Use the provided RPC, a named network's configured RPC, or the default mainnet RPC
+    let rpc_url = match (rpc, network.as_deref()) {
+        (Some(explicit), _) => explicit,
+        (None, Some(name)) => registry
+            .rpc_for_name(name)
+            .with_context(|| format!("Unknown network '{}' in config file", name))?,
+        (None, None) => {
+            println!("No RPC URL provided, using default Ethereum mainnet RPC");
+            DEFAULT_MAINNET_RPC.to_string()
+        }
+    };
 
     // Connect to provider
     let provider = Provider::<Http>::try_from(rpc_url.clone())
         .context("Failed to connect to RPC provider")?;
-    
+
     let provider = Arc::new(provider);
 
     // Get chain ID first to identify the network
     let chain_id = provider.get_chainid().await
         .context("Failed to get chain ID from network")?;
-    
+
     let network_id = chain_id.as_u64();
-    let network_name = get_network_name(network_id);
-    
+    let network_name = registry.network_name(network_id);
+
     println!("Connected to {} (Chain ID: {})", network_name, network_id);
 
+    // Build a block explorer client up front if calldata decoding was requested.
+    let explorer_client: Option<EtherscanClient> = if decode {
+        match build_explorer_client(network_id, etherscan_key) {
+            Some(client) => Some(client),
+            None => {
+                println!("Warning: --decode requested but {} has no known block explorer; calldata will be shown as raw hex", network_name);
+                None
+            }
+        }
+    } else {
+        None
+    };
+
     // Check if safe exists by attempting to get its code
     let code = provider.get_code(safe_address, None).await
         .context("Failed to query the network")?;
-    
+
     if code.is_empty() {
         bail!("Safe wallet address not found on {}", network_name);
     }
@@ -76,33 +334,37 @@ pub async fn execute(safe: String, rpc: Option<String>, hash: Option<String>, tx
             .context("Invalid custom Safe transaction pool address")?
     } else {
         // Get the network-specific transaction pool address
-        let tx_pool_address_str = get_safe_tx_pool_address(network_id);
+        let tx_pool_address_str = registry.tx_pool_address(network_id);
         println!("Using Safe transaction pool at {} for {}", tx_pool_address_str, network_name);
-        
+
         // Parse the address
-        Address::from_str(tx_pool_address_str)
+        Address::from_str(&tx_pool_address_str)
             .context("Invalid Safe transaction pool address")?
     };
-    
+
     // Verify that the transaction pool contract exists
     let contract_code = provider.get_code(tx_pool_address, None).await
         .context("Failed to query the network for transaction pool contract")?;
-    
+
     if contract_code.is_empty() {
-        bail!("Transaction pool contract not found at {}. Please verify the contract address for {} is correct.", 
+        bail!("Transaction pool contract not found at {}. Please verify the contract address for {} is correct.",
               tx_pool_address, network_name);
     }
-    
+
     // Create contract instance
     let contract = SafeTxPool::new(tx_pool_address, provider.clone());
-    
+
+    // Owners and threshold are the same for every pending transaction, so
+    // fetch them once up front.
+    let signing_status = fetch_signing_status(safe_address, provider.clone()).await?;
+
     if let Some(tx_hash) = hash {
         // Convert hash string to H256
         let hash = H256::from_str(&tx_hash)
             .context("Invalid transaction hash format")?;
-        
+
         println!("Fetching transaction with hash {} for Safe {}", tx_hash, safe);
-        
+
         // Fetch the transaction details from the Safe transaction pool
         let tx_details = match contract.get_tx_details(hash.into()).call().await {
             Ok(details) => details,
@@ -115,7 +377,7 @@ pub async fn execute(safe: String, rpc: Option<String>, hash: Option<String>, tx
         if tx_details.5 == Address::zero() {
             bail!("Transaction not found or has already been executed");
         }
-        
+
         // Fetch the signatures for this transaction
         let signatures = match contract.get_signatures(hash.into()).call().await {
             Ok(sigs) => sigs,
@@ -123,30 +385,14 @@ pub async fn execute(safe: String, rpc: Option<String>, hash: Option<String>, tx
                 bail!("Failed to fetch transaction signatures: {}. This could be because the transaction does not exist or the contract interface is incorrect.", e);
             }
         };
-        
-        // Convert signatures to hex strings
-        let signature_strings: Vec<String> = signatures.into_iter()
-            .map(|sig| format!("0x{}", hex::encode(sig.to_vec())))
-            .collect();
-        
-        // Create a structured representation of the transaction
-        let tx_data = TransactionData {
-            hash: format!("0x{}", hex::encode(hash.as_bytes())),
-            safe: format!("0x{:x}", tx_details.0),
-            to: format!("0x{:x}", tx_details.1),
-            value: tx_details.2.to_string(),
-            data: format!("0x{}", hex::encode(tx_details.3.to_vec())),
-            operation: tx_details.4 as u8,
-            proposer: format!("0x{:x}", tx_details.5),
-            nonce: tx_details.6.to_string(),
-            signatures: signature_strings,
-        };
-        
+
+        let tx_data = build_tx_data(hash, tx_details, signatures, chain_id, &signing_status, &explorer_client).await;
+
         // Convert transaction to JSON and print it
         println!("{}", serde_json::to_string_pretty(&tx_data).unwrap());
     } else {
         println!("Fetching all pending transactions for Safe {}", safe);
-        
+
         // Get all pending transaction hashes for the Safe
         // The contract doesn't paginate, just returns all hashes at once
         let all_tx_hashes_raw = match contract.get_pending_tx_hashes(safe_address).call().await {
@@ -155,64 +401,41 @@ pub async fn execute(safe: String, rpc: Option<String>, hash: Option<String>, tx
                 bail!("Failed to fetch pending transaction hashes: {}. This could be because the contract interface is incorrect or the contract does not support this function.", e);
             }
         };
-        
+
         // Convert raw bytes32 array to H256 vector
         let all_tx_hashes: Vec<H256> = all_tx_hashes_raw.into_iter()
             .map(|h| H256::from_slice(&h))
             .collect();
-        
+
         if all_tx_hashes.is_empty() {
             println!("No pending transactions found for Safe {}", safe);
             return Ok(());
         }
-        
+
         println!("Found {} pending transactions", all_tx_hashes.len());
-        
-        // Fetch details for each transaction hash
-        let mut transactions = Vec::new();
-        for tx_hash in all_tx_hashes {
-            // Fetch transaction details
-            match contract.get_tx_details(tx_hash.into()).call().await {
-                Ok(tx_details) => {
-                    // Fetch signatures
-                    let signatures = match contract.get_signatures(tx_hash.into()).call().await {
-                        Ok(sigs) => sigs.into_iter()
-                            .map(|sig| format!("0x{}", hex::encode(sig.to_vec())))
-                            .collect(),
-                        Err(_) => vec![]
-                    };
-                    
-                    // Create transaction data object
-                    let tx_data = TransactionData {
-                        hash: format!("0x{}", hex::encode(tx_hash.as_bytes())),
-                        safe: format!("0x{:x}", tx_details.0),
-                        to: format!("0x{:x}", tx_details.1),
-                        value: tx_details.2.to_string(),
-                        data: format!("0x{}", hex::encode(tx_details.3.to_vec())),
-                        operation: tx_details.4 as u8,
-                        proposer: format!("0x{:x}", tx_details.5),
-                        nonce: tx_details.6.to_string(),
-                        signatures,
-                    };
-                    
-                    transactions.push(tx_data);
-                },
+
+        let mut transactions = if no_multicall {
+            fetch_pending_sequential(&contract, &all_tx_hashes, chain_id, &signing_status, &explorer_client).await
+        } else {
+            match fetch_pending_via_multicall(&contract, provider.clone(), &all_tx_hashes, chain_id, &signing_status, &explorer_client).await {
+                Ok(transactions) => transactions,
                 Err(e) => {
-                    println!("Warning: Failed to fetch details for transaction {}: {}", tx_hash, e);
+                    println!("Warning: Multicall3 batch fetch failed ({}), falling back to one request per transaction", e);
+                    fetch_pending_sequential(&contract, &all_tx_hashes, chain_id, &signing_status, &explorer_client).await
                 }
             }
-        }
-        
+        };
+
         // Sort transactions by nonce for better readability
         transactions.sort_by(|a, b| {
             let a_nonce = a.nonce.parse::<u64>().unwrap_or(0);
             let b_nonce = b.nonce.parse::<u64>().unwrap_or(0);
             a_nonce.cmp(&b_nonce)
         });
-        
+
         // Convert the list to JSON and print it
         println!("{}", serde_json::to_string_pretty(&transactions).unwrap());
     }
 
     Ok(())
-} 
\ No newline at end of file
+}
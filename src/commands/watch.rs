@@ -0,0 +1,192 @@
+use anyhow::{Context, Result};
+use ethers::{
+    middleware::Middleware,
+    providers::{Http, Provider, Ws},
+    types::Address,
+};
+use futures::stream::select;
+use futures::StreamExt;
+use std::collections::HashSet;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::config::DEFAULT_MAINNET_RPC;
+use crate::contracts::{SafeTxPool, SignatureAddedFilter, TransactionProposedFilter};
+use crate::user_config::{load_registry, NetworkRegistry};
+
+/// A decoded `SafeTxPool` event, independent of whether it arrived over a
+/// Ws subscription or an Http polling stream.
+enum WatchEvent {
+    Proposed(TransactionProposedFilter),
+    Signed(SignatureAddedFilter),
+}
+
+pub async fn execute(
+    safe: String,
+    rpc: Option<String>,
+    tx_pool: Option<String>,
+    network: Option<String>,
+    config: Option<String>,
+) -> Result<()> {
+    let safe_address = Address::from_str(&safe).context("Invalid Safe wallet address format")?;
+
+    let registry = load_registry(config.as_deref())?;
+
+    let rpc_url = match (rpc, network.as_deref()) {
+        (Some(explicit), _) => explicit,
+        (None, Some(name)) => registry
+            .rpc_for_name(name)
+            .with_context(|| format!("Unknown network '{}' in config file", name))?,
+        (None, None) => {
+            println!("No RPC URL provided, using default Ethereum mainnet RPC");
+            DEFAULT_MAINNET_RPC.to_string()
+        }
+    };
+
+    if rpc_url.starts_with("ws://") || rpc_url.starts_with("wss://") {
+        watch_via_ws(safe_address, rpc_url, tx_pool, &registry).await
+    } else {
+        watch_via_http(safe_address, rpc_url, tx_pool, &registry).await
+    }
+}
+
+/// Seed the set of hashes this Safe already has pending before entering the
+/// event loop, so a `SignatureAdded` on a transaction proposed before `watch`
+/// started isn't silently dropped for lack of a `TransactionProposed` seen
+/// this session.
+async fn seed_known_hashes<M: Middleware + 'static>(
+    contract: &SafeTxPool<M>,
+    safe_address: Address,
+) -> HashSet<[u8; 32]> {
+    match contract.get_pending_tx_hashes(safe_address).call().await {
+        Ok(hashes) => hashes.into_iter().collect(),
+        Err(e) => {
+            println!("Warning: failed to fetch already-pending transaction hashes: {}", e);
+            HashSet::new()
+        }
+    }
+}
+
+fn resolve_tx_pool_address(network_id: u64, tx_pool: Option<String>, registry: &NetworkRegistry) -> Result<Address> {
+    match tx_pool {
+        Some(custom) => Address::from_str(&custom).context("Invalid custom Safe transaction pool address"),
+        None => Address::from_str(&registry.tx_pool_address(network_id))
+            .context("Invalid Safe transaction pool address"),
+    }
+}
+
+async fn watch_via_ws(
+    safe_address: Address,
+    rpc_url: String,
+    tx_pool: Option<String>,
+    registry: &NetworkRegistry,
+) -> Result<()> {
+    let provider = Provider::<Ws>::connect(&rpc_url)
+        .await
+        .context("Failed to connect to WebSocket RPC provider")?;
+    let provider = Arc::new(provider);
+
+    let network_id = provider.get_chainid().await.context("Failed to get chain ID from network")?.as_u64();
+    println!("Connected to {} (Chain ID: {}) over WebSocket", registry.network_name(network_id), network_id);
+
+    let tx_pool_address = resolve_tx_pool_address(network_id, tx_pool, registry)?;
+    println!("Watching Safe {} via transaction pool {}", safe_address, tx_pool_address);
+
+    let contract = SafeTxPool::new(tx_pool_address, provider);
+
+    let mut known_hashes = seed_known_hashes(&contract, safe_address).await;
+
+    let proposed = contract
+        .transaction_proposed_filter()
+        .subscribe()
+        .await
+        .context("Failed to subscribe to TransactionProposed events")?
+        .map(|log| log.map(WatchEvent::Proposed));
+    let signed = contract
+        .signature_added_filter()
+        .subscribe()
+        .await
+        .context("Failed to subscribe to SignatureAdded events")?
+        .map(|log| log.map(WatchEvent::Signed));
+
+    let mut events = select(proposed, signed);
+    while let Some(event) = events.next().await {
+        match event {
+            Ok(event) => handle_event(event, safe_address, &mut known_hashes),
+            Err(e) => println!("Warning: failed to decode event: {}", e),
+        }
+    }
+
+    Ok(())
+}
+
+async fn watch_via_http(
+    safe_address: Address,
+    rpc_url: String,
+    tx_pool: Option<String>,
+    registry: &NetworkRegistry,
+) -> Result<()> {
+    println!("No ws:// or wss:// URL given, falling back to polling over HTTP");
+
+    let provider = Provider::<Http>::try_from(rpc_url).context("Failed to connect to RPC provider")?;
+    let provider = Arc::new(provider);
+
+    let network_id = provider.get_chainid().await.context("Failed to get chain ID from network")?.as_u64();
+    println!("Connected to {} (Chain ID: {})", registry.network_name(network_id), network_id);
+
+    let tx_pool_address = resolve_tx_pool_address(network_id, tx_pool, registry)?;
+    println!("Watching Safe {} via transaction pool {}", safe_address, tx_pool_address);
+
+    let contract = SafeTxPool::new(tx_pool_address, provider);
+
+    let mut known_hashes = seed_known_hashes(&contract, safe_address).await;
+
+    let proposed = contract
+        .transaction_proposed_filter()
+        .stream()
+        .await
+        .context("Failed to watch TransactionProposed events")?
+        .map(|log| log.map(WatchEvent::Proposed));
+    let signed = contract
+        .signature_added_filter()
+        .stream()
+        .await
+        .context("Failed to watch SignatureAdded events")?
+        .map(|log| log.map(WatchEvent::Signed));
+
+    let mut events = select(proposed, signed);
+    while let Some(event) = events.next().await {
+        match event {
+            Ok(event) => handle_event(event, safe_address, &mut known_hashes),
+            Err(e) => println!("Warning: failed to decode event: {}", e),
+        }
+    }
+
+    Ok(())
+}
+
+/// `tx_pool` is a single shared contract across every Safe on a network (that's
+/// why it's configurable per-network rather than per-safe - see `config.rs`),
+/// so `SignatureAdded` events must be cross-checked against hashes this Safe
+/// has proposed before being printed, unlike `TransactionProposed` which
+/// carries the Safe address itself.
+fn handle_event(event: WatchEvent, safe_address: Address, known_hashes: &mut HashSet<[u8; 32]>) {
+    match event {
+        WatchEvent::Proposed(e) if e.safe == safe_address => {
+            known_hashes.insert(e.tx_hash);
+            println!(
+                "New pending transaction 0x{} proposed by {:?} (to {:?}, nonce {})",
+                hex::encode(e.tx_hash), e.proposer, e.to, e.nonce
+            );
+        }
+        WatchEvent::Proposed(_) => {
+            // Event emitted by the shared SafeTxPool for a different Safe - ignore.
+        }
+        WatchEvent::Signed(e) if known_hashes.contains(&e.tx_hash) => {
+            println!("New signature on 0x{} from {:?}", hex::encode(e.tx_hash), e.signer);
+        }
+        WatchEvent::Signed(_) => {
+            // Signature on a transaction we haven't seen proposed for this Safe - ignore.
+        }
+    }
+}
@@ -35,6 +35,9 @@ lazy_static! {
 // Default fallback address if network is not recognized
 pub const DEFAULT_SAFE_TX_POOL_ADDRESS: &str = "0x6b8e1f0D2c34A0AeaD9A25B6966f7C0CAD653E5c";
 
+// Multicall3 is deployed at this address on every network listed above
+pub const MULTICALL3_ADDRESS: &str = "0xcA11bde05977b3631167028862bE2a173976CA11";
+
 // Get network name from chain ID for display purposes
 pub fn get_network_name(chain_id: u64) -> &'static str {
     match chain_id {
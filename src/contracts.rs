@@ -0,0 +1,49 @@
+// Shared on-chain contract bindings used across commands.
+//
+// Keeping the `abigen!` declarations in one place means every command talks
+// to the same generated types instead of each module growing its own
+// slightly-different copy of the ABI.
+use ethers::contract::abigen;
+
+// Generate contract bindings using the exact ABI from the contract at commit 3658aca34ee38cba8e5bb9ed90927c270df8584d
+abigen!(
+    SafeTxPool,
+    r#"[
+        function getTxDetails(bytes32 txHash) external view returns (address safe, address to, uint256 value, bytes data, uint8 operation, address proposer, uint256 nonce)
+        function getSignatures(bytes32 txHash) external view returns (bytes[] memory)
+        function getPendingTxHashes(address safe) external view returns (bytes32[] memory)
+        function hasSignedTx(bytes32 txHash, address signer) external view returns (bool)
+        function signTx(bytes32 txHash, bytes signature) external
+        event TransactionProposed(bytes32 indexed txHash, address indexed safe, address proposer, address to, uint256 value, bytes data, uint8 operation, uint256 nonce)
+        event SignatureAdded(bytes32 indexed txHash, address indexed signer)
+    ]"#
+);
+
+// Minimal bindings for the Safe contract itself, used to read the owner set
+// and signing threshold so pending transactions can be checked for executability,
+// and to submit a fully-signed transaction once enough owners have signed it.
+//
+// execTransaction's signature matches the real Gnosis Safe contract exactly
+// (it never takes a nonce - that's read from the Safe's own storage counter);
+// SafeTxPool doesn't track safeTxGas/baseGas/gasPrice/gasToken/refundReceiver,
+// so callers zero-fill those, matching eip712.rs's struct hash.
+abigen!(
+    Safe,
+    r#"[
+        function getOwners() external view returns (address[] memory)
+        function getThreshold() external view returns (uint256)
+        function execTransaction(address to, uint256 value, bytes calldata data, uint8 operation, uint256 safeTxGas, uint256 baseGas, uint256 gasPrice, address gasToken, address payable refundReceiver, bytes memory signatures) external payable returns (bool success)
+    ]"#
+);
+
+// Multicall3 (https://github.com/mds1/multicall), deployed at the same
+// address on every network this CLI targets - used to batch reads against
+// SafeTxPool instead of issuing one RPC round-trip per call.
+abigen!(
+    Multicall3,
+    r#"[
+        struct Call3 { address target; bool allowFailure; bytes callData; }
+        struct Call3Result { bool success; bytes returnData; }
+        function aggregate3(Call3[] calldata calls) external payable returns (Call3Result[] memory returnData)
+    ]"#
+);
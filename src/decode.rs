@@ -0,0 +1,48 @@
+// Best-effort calldata decoding via a block explorer's verified ABI.
+//
+// Falls back to `None` whenever the explorer has no ABI for the contract, the
+// network isn't supported by `ethers-etherscan`, or the selector doesn't
+// match anything in the ABI - callers should render the raw hex in that case.
+use ethers::abi::{Abi, Token};
+use ethers::etherscan::Client;
+use ethers::types::{Address, Bytes, Chain};
+
+#[derive(serde::Serialize, Clone)]
+pub struct DecodedCall {
+    pub function: String,
+    pub args: Vec<String>,
+}
+
+/// Build an Etherscan-family client for `chain_id`, if that network is one
+/// `ethers-etherscan` knows how to reach.
+pub fn build_explorer_client(chain_id: u64, api_key: Option<String>) -> Option<Client> {
+    let chain = Chain::try_from(chain_id).ok()?;
+    let mut builder = Client::builder().chain(chain).ok()?;
+    if let Some(key) = api_key {
+        builder = builder.with_api_key(key);
+    }
+    builder.build().ok()
+}
+
+/// Decode `data` as a call into `to`, using `to`'s verified ABI.
+pub async fn decode_calldata(client: &Client, to: Address, data: &Bytes) -> Option<DecodedCall> {
+    if data.len() < 4 {
+        return None;
+    }
+
+    let abi: Abi = client.contract_abi(to).await.ok()?;
+    let selector = &data.as_ref()[0..4];
+    let function = abi.functions().find(|f| f.short_signature() == selector)?;
+
+    let tokens = function.decode_input(&data.as_ref()[4..]).ok()?;
+    let args = tokens.iter().map(format_token).collect();
+
+    Some(DecodedCall {
+        function: function.signature(),
+        args,
+    })
+}
+
+fn format_token(token: &Token) -> String {
+    format!("{:?}", token)
+}
@@ -0,0 +1,22 @@
+// Gas price selection for transactions submitted by `exec`.
+use ethers::middleware::gas_oracle::{Etherscan, GasOracle, ProviderOracle};
+use ethers::providers::{Http, Provider};
+use ethers::types::Chain;
+use std::sync::Arc;
+
+/// Pick a gas oracle for `chain_id`: an Etherscan-family oracle when the
+/// network is supported and an API key was provided, otherwise fall back to
+/// reading the gas price directly from the RPC provider.
+pub fn build_gas_oracle(
+    chain_id: u64,
+    provider: Arc<Provider<Http>>,
+    etherscan_key: Option<String>,
+) -> Box<dyn GasOracle> {
+    if let (Ok(chain), Some(key)) = (Chain::try_from(chain_id), etherscan_key) {
+        if let Ok(client) = ethers::etherscan::Client::new(chain, key) {
+            return Box::new(Etherscan::new(client));
+        }
+    }
+
+    Box::new(ProviderOracle::new(provider))
+}
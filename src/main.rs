@@ -3,6 +3,12 @@ use anyhow::Result;
 
 mod commands;
 mod config;
+mod contracts;
+mod decode;
+mod eip712;
+mod gas_oracle;
+mod signature;
+mod user_config;
 
 /// A CLI tool for Ethereum Safe operations
 #[derive(Parser)]
@@ -37,6 +43,162 @@ enum Commands {
         /// Custom Safe transaction pool address (0x...) - Optional
         #[arg(long)]
         tx_pool: Option<String>,
+
+        /// Decode calldata into a human-readable function call using the target contract's verified ABI
+        #[arg(long)]
+        decode: bool,
+
+        /// Etherscan (or Etherscan-family) API key used to fetch verified ABIs for --decode
+        #[arg(long)]
+        etherscan_key: Option<String>,
+
+        /// Disable Multicall3 batching and fetch each pending transaction with its own RPC requests
+        #[arg(long)]
+        no_multicall: bool,
+
+        /// Name of a network from the config file to connect to - Optional, used when --rpc is omitted
+        #[arg(long)]
+        network: Option<String>,
+
+        /// Path to a config file of user-defined networks - Optional, defaults to ~/.vito/config.toml
+        #[arg(long)]
+        config: Option<String>,
+    },
+
+    /// Sign a pending Safe transaction and submit the signature to the transaction pool
+    Sign {
+        /// Ethereum Safe wallet address (0x...)
+        #[arg(short, long)]
+        safe: String,
+
+        /// Provider RPC URL (http:// or https://) - Optional, defaults to Ethereum mainnet
+        #[arg(short, long)]
+        rpc: Option<String>,
+
+        /// Transaction hash (0x...) to sign
+        #[arg(short = 't', long)]
+        hash: String,
+
+        /// Custom Safe transaction pool address (0x...) - Optional
+        #[arg(long)]
+        tx_pool: Option<String>,
+
+        /// Sign using a Ledger hardware wallet
+        #[arg(long)]
+        ledger: bool,
+
+        /// HD derivation path for the Ledger signer - Optional, defaults to the first Ledger Live account
+        #[arg(long)]
+        derivation_path: Option<String>,
+
+        /// Path to an encrypted JSON keystore to sign with
+        #[arg(long)]
+        keystore: Option<String>,
+
+        /// Raw private key to sign with (0x...)
+        #[arg(long)]
+        private_key: Option<String>,
+
+        /// Name of a network from the config file to connect to - Optional, used when --rpc is omitted
+        #[arg(long)]
+        network: Option<String>,
+
+        /// Path to a config file of user-defined networks - Optional, defaults to ~/.vito/config.toml
+        #[arg(long)]
+        config: Option<String>,
+    },
+
+    /// Execute a pending Safe transaction that has enough signatures to meet the threshold
+    Exec {
+        /// Ethereum Safe wallet address (0x...)
+        #[arg(short, long)]
+        safe: String,
+
+        /// Provider RPC URL (http:// or https://) - Optional, defaults to Ethereum mainnet
+        #[arg(short, long)]
+        rpc: Option<String>,
+
+        /// Transaction hash (0x...) to execute
+        #[arg(short = 't', long)]
+        hash: String,
+
+        /// Custom Safe transaction pool address (0x...) - Optional
+        #[arg(long)]
+        tx_pool: Option<String>,
+
+        /// Etherscan (or Etherscan-family) API key used for gas price estimation - Optional
+        #[arg(long)]
+        etherscan_key: Option<String>,
+
+        /// Submit the transaction using a Ledger hardware wallet
+        #[arg(long)]
+        ledger: bool,
+
+        /// HD derivation path for the Ledger signer - Optional, defaults to the first Ledger Live account
+        #[arg(long)]
+        derivation_path: Option<String>,
+
+        /// Path to an encrypted JSON keystore to submit the transaction with
+        #[arg(long)]
+        keystore: Option<String>,
+
+        /// Raw private key to submit the transaction with (0x...)
+        #[arg(long)]
+        private_key: Option<String>,
+
+        /// Name of a network from the config file to connect to - Optional, used when --rpc is omitted
+        #[arg(long)]
+        network: Option<String>,
+
+        /// Path to a config file of user-defined networks - Optional, defaults to ~/.vito/config.toml
+        #[arg(long)]
+        config: Option<String>,
+    },
+
+    /// Stream new pending transactions and signatures for a Safe as they happen
+    Watch {
+        /// Ethereum Safe wallet address (0x...)
+        #[arg(short, long)]
+        safe: String,
+
+        /// Provider RPC URL - use ws:// or wss:// for live push updates, otherwise falls back to HTTP polling
+        #[arg(short, long)]
+        rpc: Option<String>,
+
+        /// Custom Safe transaction pool address (0x...) - Optional
+        #[arg(long)]
+        tx_pool: Option<String>,
+
+        /// Name of a network from the config file to connect to - Optional, used when --rpc is omitted
+        #[arg(long)]
+        network: Option<String>,
+
+        /// Path to a config file of user-defined networks - Optional, defaults to ~/.vito/config.toml
+        #[arg(long)]
+        config: Option<String>,
+    },
+
+    /// Add or update a network entry in the user config file
+    AddNetwork {
+        /// Chain ID of the network
+        #[arg(long)]
+        chain_id: u64,
+
+        /// Display name for the network
+        #[arg(long)]
+        name: String,
+
+        /// Default RPC URL for the network
+        #[arg(long)]
+        rpc: String,
+
+        /// SafeTxPool contract address (0x...) deployed on the network
+        #[arg(long)]
+        tx_pool: String,
+
+        /// Path to the config file to update - Optional, defaults to ~/.vito/config.toml
+        #[arg(long)]
+        config: Option<String>,
     },
 }
 
@@ -45,8 +207,20 @@ async fn main() -> Result<()> {
     let cli = Cli::parse();
     
     match cli.command {
-        Commands::Tx { safe, rpc, hash, tx_pool } => {
-            commands::tx::execute(safe, rpc, hash, tx_pool).await?;
+        Commands::Tx { safe, rpc, hash, tx_pool, decode, etherscan_key, no_multicall, network, config } => {
+            commands::tx::execute(safe, rpc, hash, tx_pool, decode, etherscan_key, no_multicall, network, config).await?;
+        }
+        Commands::Sign { safe, rpc, hash, tx_pool, ledger, derivation_path, keystore, private_key, network, config } => {
+            commands::sign::execute(safe, rpc, hash, tx_pool, ledger, derivation_path, keystore, private_key, network, config).await?;
+        }
+        Commands::Exec { safe, rpc, hash, tx_pool, etherscan_key, ledger, derivation_path, keystore, private_key, network, config } => {
+            commands::exec::execute(safe, rpc, hash, tx_pool, etherscan_key, ledger, derivation_path, keystore, private_key, network, config).await?;
+        }
+        Commands::Watch { safe, rpc, tx_pool, network, config } => {
+            commands::watch::execute(safe, rpc, tx_pool, network, config).await?;
+        }
+        Commands::AddNetwork { chain_id, name, rpc, tx_pool, config } => {
+            commands::add_network::execute(chain_id, name, rpc, tx_pool, config)?;
         }
     }
     
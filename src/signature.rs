@@ -0,0 +1,141 @@
+// Recovery of Safe owner signatures per the Safe contract's signature encoding
+// (see `checkNSignatures` in the Gnosis Safe contracts): a signature's `v`
+// byte selects how the remaining 64 bytes are interpreted.
+use anyhow::{bail, Result};
+use ethers::types::{Address, Signature, H256, U256};
+use ethers::utils::hash_message;
+
+/// How a recovered signature was produced, per the Safe signature scheme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SignatureKind {
+    /// `v == 0`: approved by another contract (r holds the contract address).
+    Contract,
+    /// `v == 1`: pre-approved via `approveHash` (r holds the approver address).
+    ApprovedHash,
+    /// `v in {27, 28}`: a plain ECDSA signature over the EIP-712 digest.
+    Eip712,
+    /// `v in {31, 32}`: an `eth_sign`-prefixed signature over the digest.
+    EthSign,
+}
+
+pub struct RecoveredSignature {
+    pub signer: Address,
+    pub kind: SignatureKind,
+}
+
+/// Recover the signing address (and signature type) of a single Safe
+/// signature over the given EIP-712 `digest`.
+pub fn recover_signer(digest: H256, signature: &[u8]) -> Result<RecoveredSignature> {
+    if signature.len() != 65 {
+        bail!("Unexpected signature length: expected 65 bytes, got {}", signature.len());
+    }
+
+    let v = signature[64];
+    match v {
+        0 => Ok(RecoveredSignature {
+            signer: Address::from_slice(&signature[12..32]),
+            kind: SignatureKind::Contract,
+        }),
+        1 => Ok(RecoveredSignature {
+            signer: Address::from_slice(&signature[12..32]),
+            kind: SignatureKind::ApprovedHash,
+        }),
+        v if v >= 31 => {
+            let sig = Signature {
+                r: U256::from_big_endian(&signature[0..32]),
+                s: U256::from_big_endian(&signature[32..64]),
+                v: (v - 4) as u64,
+            };
+            let signer = sig
+                .recover(hash_message(digest.as_bytes()))
+                .map_err(|e| anyhow::anyhow!("Failed to recover eth_sign signature: {}", e))?;
+            Ok(RecoveredSignature { signer, kind: SignatureKind::EthSign })
+        }
+        _ => {
+            let sig = Signature {
+                r: U256::from_big_endian(&signature[0..32]),
+                s: U256::from_big_endian(&signature[32..64]),
+                v: v as u64,
+            };
+            let signer = sig
+                .recover(digest)
+                .map_err(|e| anyhow::anyhow!("Failed to recover EIP-712 signature: {}", e))?;
+            Ok(RecoveredSignature { signer, kind: SignatureKind::Eip712 })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethers::signers::{LocalWallet, Signer};
+    use ethers::utils::keccak256;
+
+    fn test_digest() -> H256 {
+        H256::from(keccak256(b"recover_signer test digest"))
+    }
+
+    #[tokio::test]
+    async fn recovers_eip712_signature() {
+        let wallet = LocalWallet::new(&mut rand::thread_rng());
+        let digest = test_digest();
+
+        let signature = wallet.sign_hash(digest).expect("sign_hash should succeed");
+        let recovered = recover_signer(digest, &signature.to_vec()).expect("recovery should succeed");
+
+        assert_eq!(recovered.signer, wallet.address());
+        assert_eq!(recovered.kind, SignatureKind::Eip712);
+    }
+
+    #[tokio::test]
+    async fn recovers_eth_sign_signature() {
+        let wallet = LocalWallet::new(&mut rand::thread_rng());
+        let digest = test_digest();
+
+        let signature = wallet
+            .sign_message(digest.as_bytes())
+            .await
+            .expect("sign_message should succeed");
+        let mut signature_bytes = signature.to_vec();
+        signature_bytes[64] += 4;
+
+        let recovered = recover_signer(digest, &signature_bytes).expect("recovery should succeed");
+
+        assert_eq!(recovered.signer, wallet.address());
+        assert_eq!(recovered.kind, SignatureKind::EthSign);
+    }
+
+    #[test]
+    fn recovers_contract_signature() {
+        let signer = Address::from_slice(&[0x42; 20]);
+        let mut raw = vec![0u8; 65];
+        raw[12..32].copy_from_slice(signer.as_bytes());
+        raw[64] = 0;
+
+        let recovered = recover_signer(test_digest(), &raw).expect("recovery should succeed");
+
+        assert_eq!(recovered.signer, signer);
+        assert_eq!(recovered.kind, SignatureKind::Contract);
+    }
+
+    #[test]
+    fn recovers_approved_hash_signature() {
+        let signer = Address::from_slice(&[0x99; 20]);
+        let mut raw = vec![0u8; 65];
+        raw[12..32].copy_from_slice(signer.as_bytes());
+        raw[64] = 1;
+
+        let recovered = recover_signer(test_digest(), &raw).expect("recovery should succeed");
+
+        assert_eq!(recovered.signer, signer);
+        assert_eq!(recovered.kind, SignatureKind::ApprovedHash);
+    }
+
+    #[test]
+    fn rejects_wrong_length_signature() {
+        let raw = vec![0u8; 64];
+        let err = recover_signer(test_digest(), &raw).unwrap_err();
+        assert!(err.to_string().contains("Unexpected signature length"));
+    }
+}
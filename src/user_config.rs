@@ -0,0 +1,108 @@
+// User-overridable network configuration, loaded from `~/.vito/config.toml`
+// (or a path given via `--config`) and merged over the built-in defaults in
+// `config.rs`. This lets users target chains or SafeTxPool deployments the
+// binary doesn't ship with, without recompiling.
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use crate::config::{get_network_name, get_safe_tx_pool_address};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NetworkConfig {
+    pub chain_id: u64,
+    pub name: String,
+    pub rpc: String,
+    pub tx_pool_address: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UserConfig {
+    #[serde(default)]
+    pub networks: Vec<NetworkConfig>,
+}
+
+/// The built-in network table merged with any user-supplied overrides.
+pub struct NetworkRegistry {
+    user_networks: Vec<NetworkConfig>,
+}
+
+impl NetworkRegistry {
+    pub fn network_name(&self, chain_id: u64) -> String {
+        match self.find(chain_id) {
+            Some(network) => network.name.clone(),
+            None => get_network_name(chain_id).to_string(),
+        }
+    }
+
+    pub fn tx_pool_address(&self, chain_id: u64) -> String {
+        match self.find(chain_id) {
+            Some(network) => network.tx_pool_address.clone(),
+            None => get_safe_tx_pool_address(chain_id).to_string(),
+        }
+    }
+
+    /// Look up a user-defined network's default RPC URL by name, so a
+    /// network can be selected with `--network <name>` before the chain id
+    /// (and thus the rest of the registry) is known.
+    pub fn rpc_for_name(&self, name: &str) -> Option<String> {
+        self.user_networks
+            .iter()
+            .find(|network| network.name == name)
+            .map(|network| network.rpc.clone())
+    }
+
+    fn find(&self, chain_id: u64) -> Option<&NetworkConfig> {
+        self.user_networks.iter().find(|network| network.chain_id == chain_id)
+    }
+}
+
+/// Default location of the user config file: `~/.vito/config.toml`.
+pub fn default_config_path() -> PathBuf {
+    dirs::home_dir().unwrap_or_else(|| PathBuf::from(".")).join(".vito").join("config.toml")
+}
+
+fn resolve_path(config_path: Option<&str>) -> PathBuf {
+    config_path.map(PathBuf::from).unwrap_or_else(default_config_path)
+}
+
+fn read_user_config(path: &PathBuf) -> Result<UserConfig> {
+    if !path.exists() {
+        return Ok(UserConfig::default());
+    }
+
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read config file at {}", path.display()))?;
+    toml::from_str(&contents)
+        .with_context(|| format!("Failed to parse config file at {}", path.display()))
+}
+
+/// Load the merged network registry, consulting `config_path` (or the
+/// default `~/.vito/config.toml`) for user overrides.
+pub fn load_registry(config_path: Option<&str>) -> Result<NetworkRegistry> {
+    let path = resolve_path(config_path);
+    let user_config = read_user_config(&path)?;
+
+    Ok(NetworkRegistry { user_networks: user_config.networks })
+}
+
+/// Add or replace a network entry in the user config file, creating the file
+/// (and its parent directory) if it doesn't exist yet.
+pub fn add_network(config_path: Option<&str>, network: NetworkConfig) -> Result<PathBuf> {
+    let path = resolve_path(config_path);
+    let mut user_config = read_user_config(&path)?;
+
+    user_config.networks.retain(|existing| existing.chain_id != network.chain_id);
+    user_config.networks.push(network);
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create config directory at {}", parent.display()))?;
+    }
+
+    let serialized = toml::to_string_pretty(&user_config).context("Failed to serialize config file")?;
+    std::fs::write(&path, serialized)
+        .with_context(|| format!("Failed to write config file at {}", path.display()))?;
+
+    Ok(path)
+}